@@ -61,6 +61,24 @@ pub fn ln_binomial(n: u64, k: u64) -> f64 {
     }
 }
 
+/// Computes the natural logarithm of the generalized binomial coefficient
+/// `ln(n choose k)` for real-valued `n` and `k`, via `gamma::ln_gamma`.
+///
+/// Unlike [`ln_binomial`], `n` need not be a non-negative integer, which is
+/// what distributions like the negative binomial need for `ln C(x+r-1, x)`
+/// with a non-integer `r`.
+///
+/// # Remarks
+///
+/// Returns `f64::NEG_INFINITY` if `k < 0` or `n - k < 0`
+pub fn ln_binomial_real(n: f64, k: f64) -> f64 {
+    if k < 0.0 || n - k < 0.0 {
+        f64::NEG_INFINITY
+    } else {
+        gamma::ln_gamma(n + 1.0) - gamma::ln_gamma(k + 1.0) - gamma::ln_gamma(n - k + 1.0)
+    }
+}
+
 /// Computes the multinomial coefficient: `n choose n1, n2, n3, ...`
 ///
 /// # Panics
@@ -85,6 +103,53 @@ pub fn checked_multinomial(n: u64, ni: &[u64]) -> Option<f64> {
     }
 }
 
+/// Computes the factorial function `n -> n!` exactly using `u128`
+/// arithmetic.
+///
+/// # Remarks
+///
+/// Returns `None` if `n!` overflows `u128` (i.e. `n > 34`)
+pub fn checked_factorial_u128(n: u64) -> Option<u128> {
+    (1..=n as u128).try_fold(1u128, |acc, i| acc.checked_mul(i))
+}
+
+/// Greatest common divisor of two `u128` values, via the Euclidean algorithm.
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Computes the binomial coefficient `n choose k` exactly using `u128`
+/// arithmetic, unlike [`binomial`] which rounds a floating-point
+/// approximation and can be off by one for large values.
+///
+/// Uses the multiplicative recurrence `C(n,k) = C(n,k-1)*(n-k+1)/k`, reducing
+/// `k` to `min(k, n-k)` first. Each step divides out `gcd(result, k)` before
+/// multiplying by the next numerator rather than after, so the intermediate
+/// value never exceeds the final coefficient's magnitude (multiplying first
+/// and dividing after can overflow `u128` even when `C(n,k)` itself fits).
+///
+/// # Remarks
+///
+/// Returns `None` if `k > n` or if `C(n,k)` overflows `u128`
+pub fn checked_binomial_u128(n: u64, k: u64) -> Option<u128> {
+    if k > n {
+        return None;
+    }
+    let k = k.min(n - k);
+
+    let mut result = 1u128;
+    for i in 0..k {
+        let numerator = n as u128 - i as u128;
+        let denominator = i as u128 + 1;
+        let g = gcd_u128(result, denominator);
+        result = (result / g).checked_mul(numerator / (denominator / g))?;
+    }
+    Some(result)
+}
+
 // Initialization for pre-computed cache of 171 factorial
 // values 0!...170!
 const FCACHE: [f64; MAX_FACTORIAL + 1] = {
@@ -166,6 +231,43 @@ mod tests {
         assert_eq!(ln_binomial(5, 7), 0f64.ln());
     }
 
+    #[test]
+    fn test_ln_binomial_real() {
+        prec::assert_abs_diff_eq!(ln_binomial_real(5.0, 2.0), 10f64.ln(), epsilon = 1e-11);
+        prec::assert_abs_diff_eq!(ln_binomial_real(7.0, 3.0), 35f64.ln(), epsilon = 1e-11);
+        // non-integer `n`, as used by a real-valued negative binomial `r`
+        prec::assert_abs_diff_eq!(ln_binomial_real(3.5, 2.0), 1.475906, epsilon = 1e-4);
+        assert_eq!(ln_binomial_real(5.0, -1.0), f64::NEG_INFINITY);
+        assert_eq!(ln_binomial_real(5.0, 7.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_checked_factorial_u128() {
+        assert_eq!(checked_factorial_u128(0), Some(1));
+        assert_eq!(checked_factorial_u128(1), Some(1));
+        assert_eq!(checked_factorial_u128(5), Some(120));
+        assert_eq!(checked_factorial_u128(34), Some(295232799039604140847618609643520000000));
+        assert_eq!(checked_factorial_u128(35), None);
+    }
+
+    #[test]
+    fn test_checked_binomial_u128() {
+        assert_eq!(checked_binomial_u128(1, 1), Some(1));
+        assert_eq!(checked_binomial_u128(5, 2), Some(10));
+        assert_eq!(checked_binomial_u128(7, 3), Some(35));
+        assert_eq!(checked_binomial_u128(1, 0), Some(1));
+        assert_eq!(checked_binomial_u128(0, 1), None);
+        assert_eq!(checked_binomial_u128(5, 7), None);
+        // large enough that the f64-based `binomial` loses exactness
+        assert_eq!(checked_binomial_u128(100, 50), Some(100891344545564193334812497256));
+        // `result * (n - i)` overflows `u128` here before the final division,
+        // even though `C(26000, 10)` itself fits comfortably.
+        assert_eq!(
+            checked_binomial_u128(26000, 10),
+            Some(38834587078782847714140746412312267400)
+        );
+    }
+
     #[test]
     fn test_multinomial() {
         assert_eq!(1.0, multinomial(1, &[1, 0]));
@@ -0,0 +1,117 @@
+use super::Alternative;
+use crate::distribution::{Binomial, BinomialError, Discrete, DiscreteCDF};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum BinomialTestError {
+    /// `trials`/`p` do not describe a valid [`Binomial`] distribution.
+    InvalidBinomial(BinomialError),
+}
+
+impl core::fmt::Display for BinomialTestError {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            BinomialTestError::InvalidBinomial(b_err) => {
+                write!(f, "Cannot create a Binomial distribution from `trials` and `p`: '{b_err}'")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BinomialTestError {}
+
+impl From<BinomialError> for BinomialTestError {
+    fn from(value: BinomialError) -> Self {
+        Self::InvalidBinomial(value)
+    }
+}
+
+/// Perform an exact one-sample binomial test of the null hypothesis that the
+/// true success probability of `trials` Bernoulli trials is `p`, having
+/// observed `successes`.
+///
+/// For [`Alternative::Less`]/[`Alternative::Greater`] the p-value is the
+/// corresponding one-sided tail of the `Binomial(trials, p)` CDF evaluated at
+/// `successes`. For [`Alternative::TwoSided`] the p-value is the sum of the
+/// pmf over every outcome that is at least as extreme (i.e. at least as
+/// unlikely) as the observed one, which is the standard exact two-sided
+/// binomial test.
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::binomial_test;
+/// use statrs::stats_tests::Alternative;
+/// let p_value = binomial_test(7, 10, 0.5, Alternative::TwoSided).unwrap();
+/// ```
+pub fn binomial_test(
+    successes: u64,
+    trials: u64,
+    p: f64,
+    alternative: Alternative,
+) -> Result<f64, BinomialTestError> {
+    let dist = Binomial::new(p, trials)?;
+
+    let p_value = match alternative {
+        Alternative::Less => dist.cdf(successes),
+        Alternative::Greater => {
+            if successes == 0 {
+                1.0
+            } else {
+                1.0 - dist.cdf(successes - 1)
+            }
+        }
+        Alternative::TwoSided => {
+            // Relative tolerance guards against floating-point error on
+            // near-ties, mirroring the Fisher two-sided "sum of
+            // equally-or-less-probable outcomes" logic.
+            let p_obs = dist.pmf(successes);
+            let threshold = p_obs * (1.0 + 1e-7);
+            (0..=trials)
+                .map(|k| dist.pmf(k))
+                .filter(|&p_k| p_k <= threshold)
+                .sum::<f64>()
+                .min(1.0)
+        }
+    };
+
+    Ok(p_value)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prec;
+
+    #[test]
+    fn test_binomial_test_less() {
+        let p_value = binomial_test(3, 10, 0.5, Alternative::Less).unwrap();
+        prec::assert_abs_diff_eq!(p_value, 0.171875, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_binomial_test_greater() {
+        let p_value = binomial_test(7, 10, 0.5, Alternative::Greater).unwrap();
+        prec::assert_abs_diff_eq!(p_value, 0.171875, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_binomial_test_two_sided_matches_r() {
+        // R: binom.test(7, 10, 0.5)$p.value
+        let p_value = binomial_test(7, 10, 0.5, Alternative::TwoSided).unwrap();
+        prec::assert_abs_diff_eq!(p_value, 0.34375, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_binomial_test_two_sided_clamped_to_one() {
+        let p_value = binomial_test(5, 10, 0.5, Alternative::TwoSided).unwrap();
+        prec::assert_abs_diff_eq!(p_value, 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_binomial_test_invalid_p() {
+        assert!(binomial_test(3, 10, 1.5, Alternative::TwoSided).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use super::Alternative;
 use crate::distribution::{Discrete, DiscreteCDF, Hypergeometric, HypergeometricError};
+use crate::function::factorial::ln_binomial;
 
 const EPSILON: f64 = 1.0 - 1e-4;
 
@@ -238,6 +239,166 @@ pub fn fishers_exact(
     Ok(p_value.min(1.0))
 }
 
+/// Log-probability (up to the normalizing constant) of `k` successes under
+/// Fisher's noncentral hypergeometric distribution with log odds ratio `log_psi`.
+fn log_weight(n1: u64, n2: u64, n: u64, k: u64, log_psi: f64) -> f64 {
+    ln_binomial(n1, k) + ln_binomial(n2, n - k) + k as f64 * log_psi
+}
+
+/// `ln(sum(exp(values)))`, computed in a shift-invariant way so that large
+/// positive or negative `log_psi` don't overflow/underflow the individual terms.
+fn log_sum_exp(values: impl Iterator<Item = f64>) -> f64 {
+    let values: Vec<f64> = values.collect();
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    if max == f64::NEG_INFINITY {
+        return f64::NEG_INFINITY;
+    }
+    max + values.iter().map(|v| (v - max).exp()).sum::<f64>().ln()
+}
+
+/// Normalized pmf of Fisher's noncentral hypergeometric distribution at every
+/// point of its support `k_min..=k_max`, for the given log odds ratio.
+fn noncentral_hypergeometric_pmf(n1: u64, n2: u64, n: u64, k_min: u64, k_max: u64, log_psi: f64) -> Vec<f64> {
+    let log_weights: Vec<f64> = (k_min..=k_max).map(|k| log_weight(n1, n2, n, k, log_psi)).collect();
+    let log_norm = log_sum_exp(log_weights.iter().copied());
+    log_weights.iter().map(|w| (w - log_norm).exp()).collect()
+}
+
+/// `E[X; psi]` under Fisher's noncentral hypergeometric distribution, which is
+/// monotone increasing in `log_psi` and used to define the conditional MLE.
+fn noncentral_hypergeometric_mean(n1: u64, n2: u64, n: u64, k_min: u64, k_max: u64, log_psi: f64) -> f64 {
+    noncentral_hypergeometric_pmf(n1, n2, n, k_min, k_max, log_psi)
+        .iter()
+        .zip(k_min..=k_max)
+        .map(|(p, k)| p * k as f64)
+        .sum()
+}
+
+/// Solves `f(t) = target` for a monotone increasing `f` by bisection, expanding
+/// the initial bracket outward until it straddles the target.
+fn solve_monotone(target: f64, f: impl Fn(f64) -> f64) -> f64 {
+    let (mut lo, mut hi) = (-1.0_f64, 1.0_f64);
+    while f(lo) > target && lo > -700.0 {
+        lo *= 2.0;
+    }
+    while f(hi) < target && hi < 700.0 {
+        hi *= 2.0;
+    }
+
+    for _ in 0..200 {
+        let mid = 0.5 * (lo + hi);
+        if f(mid) < target {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+/// Computes the conditional maximum-likelihood estimate of the odds ratio for
+/// a 2x2 contingency table, together with a `conf_level` confidence interval,
+/// matching R's `fisher.test`.
+///
+/// Conditioning on both margins, `table[0]` follows Fisher's noncentral
+/// hypergeometric distribution with parameter `psi` (the odds ratio). The
+/// conditional MLE is the `psi` for which `E[X; psi]` equals the observed
+/// count, found by bisection on `t = ln(psi)` since the mean is monotone
+/// increasing in `t`. The confidence interval endpoints solve the usual tail
+/// equations on the same noncentral hypergeometric family.
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::fishers_exact_conditional;
+/// use statrs::stats_tests::Alternative;
+/// let table = [3, 1, 1, 3];
+/// let (odds_ratio, (low, high)) =
+///     fishers_exact_conditional(&table, Alternative::TwoSided, 0.95).unwrap();
+/// ```
+pub fn fishers_exact_conditional(
+    table: &[u64; 4],
+    alternative: Alternative,
+    conf_level: f64,
+) -> Result<(f64, (f64, f64)), FishersExactTestError> {
+    // If both values in a row or column are zero, the odds ratio is
+    // unidentifiable: the table's margins pin `table[0]` to a single value
+    // regardless of the odds ratio, so the likelihood carries no information
+    // about it. As in `fishers_exact_with_odds_ratio`, the odds ratio is `NaN`
+    // for this class of input; the interval is the whole range `(0, inf)`.
+    match table {
+        [0, _, 0, _] | [_, 0, _, 0] => return Ok((f64::NAN, (0.0, f64::INFINITY))), // both 0 in a row
+        [0, 0, _, _] | [_, _, 0, 0] => return Ok((f64::NAN, (0.0, f64::INFINITY))), // both 0 in a column
+        _ => (),                                                                   // continue
+    }
+
+    let n1 = table[0] + table[1];
+    let n2 = table[2] + table[3];
+    let n = table[0] + table[2];
+    let x = table[0];
+
+    // Validate the table the same way `fishers_exact` does.
+    Hypergeometric::new(n1 + n2, n1, n)?;
+
+    let k_min = n.saturating_sub(n2);
+    let k_max = n1.min(n);
+
+    let mean_at = |log_psi: f64| noncentral_hypergeometric_mean(n1, n2, n, k_min, k_max, log_psi);
+
+    let mle = if x == k_min {
+        0.0
+    } else if x == k_max {
+        f64::INFINITY
+    } else {
+        solve_monotone(x as f64, mean_at).exp()
+    };
+
+    let alpha = 1.0 - conf_level;
+
+    let upper_tail_at = |log_psi: f64| -> f64 {
+        noncentral_hypergeometric_pmf(n1, n2, n, k_min, k_max, log_psi)
+            .iter()
+            .zip(k_min..=k_max)
+            .filter(|&(_, k)| k >= x)
+            .map(|(p, _)| p)
+            .sum()
+    };
+    let lower_tail_at = |log_psi: f64| -> f64 {
+        noncentral_hypergeometric_pmf(n1, n2, n, k_min, k_max, log_psi)
+            .iter()
+            .zip(k_min..=k_max)
+            .filter(|&(_, k)| k <= x)
+            .map(|(p, _)| p)
+            .sum()
+    };
+
+    let lower_alpha = match alternative {
+        Alternative::Greater => alpha,
+        _ => alpha / 2.0,
+    };
+    let upper_alpha = match alternative {
+        Alternative::Less => alpha,
+        _ => alpha / 2.0,
+    };
+
+    let low = if x == k_min || alternative == Alternative::Less {
+        0.0
+    } else {
+        // `upper_tail_at` is monotone increasing in `log_psi`, and we want the
+        // `psi` for which it equals `lower_alpha`.
+        solve_monotone(lower_alpha, upper_tail_at).exp()
+    };
+
+    let high = if x == k_max || alternative == Alternative::Greater {
+        f64::INFINITY
+    } else {
+        // `lower_tail_at` is monotone *decreasing* in `log_psi`, so solve on
+        // its negation to recover a monotone increasing target function.
+        solve_monotone(-upper_alpha, |log_psi| -lower_tail_at(log_psi)).exp()
+    };
+
+    Ok((mle, (low, high)))
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -396,4 +557,59 @@ mod tests {
         prec::assert_abs_diff_eq!(p_value, 0.9963034765672599);
         prec::assert_abs_diff_eq!(odds_ratio, 7.5);
     }
+
+    #[test]
+    fn test_fishers_exact_conditional() {
+        // R's `?fisher.test` tea-tasting example:
+        // matrix(c(3, 1, 1, 3), nrow = 2), which is our table [3, 1, 1, 3].
+        let table = [3, 1, 1, 3];
+        let (odds_ratio, (low, high)) =
+            fishers_exact_conditional(&table, Alternative::TwoSided, 0.95).unwrap();
+        prec::assert_abs_diff_eq!(odds_ratio, 6.408309, epsilon = 1e-2);
+        prec::assert_abs_diff_eq!(low, 0.2117329, epsilon = 1e-2);
+        prec::assert_abs_diff_eq!(high, 621.9337505, epsilon = 1.0);
+    }
+
+    #[test]
+    fn test_fishers_exact_conditional_one_sided() {
+        // One-sided intervals always pin the unconstrained endpoint to its
+        // boundary value, regardless of where `x` falls in the support.
+        let table = [3, 1, 1, 3];
+        let (_, (low, high)) =
+            fishers_exact_conditional(&table, Alternative::Less, 0.95).unwrap();
+        assert_eq!(low, 0.0);
+        assert!(high.is_finite());
+
+        let (_, (low, high)) =
+            fishers_exact_conditional(&table, Alternative::Greater, 0.95).unwrap();
+        assert_eq!(high, f64::INFINITY);
+        assert!(low.is_finite() && low > 0.0);
+    }
+
+    #[test]
+    fn test_fishers_exact_conditional_boundary() {
+        // When the observed count sits at either end of the support, the MLE
+        // and the corresponding CI endpoint collapse to 0 or infinity.
+        let table = [0, 5, 5, 0];
+        let (odds_ratio, (low, high)) =
+            fishers_exact_conditional(&table, Alternative::TwoSided, 0.95).unwrap();
+        assert_eq!(odds_ratio, 0.0);
+        assert_eq!(low, 0.0);
+        assert!(high.is_finite());
+    }
+
+    #[test]
+    fn test_fishers_exact_conditional_degenerate_margin() {
+        // A whole row or column of zeros makes the odds ratio unidentifiable:
+        // the margins pin `table[0]` regardless of `psi`, so there's no MLE.
+        let cases = [[0, 0, 1, 2], [1, 2, 0, 0], [1, 0, 2, 0], [0, 1, 0, 2]];
+
+        for table in cases.iter() {
+            let (odds_ratio, (low, high)) =
+                fishers_exact_conditional(table, Alternative::TwoSided, 0.95).unwrap();
+            assert!(odds_ratio.is_nan());
+            assert_eq!(low, 0.0);
+            assert_eq!(high, f64::INFINITY);
+        }
+    }
 }
@@ -0,0 +1,223 @@
+use super::Alternative;
+use crate::distribution::DiscreteCDF;
+
+/// Converts a Kolmogorov-Smirnov statistic `d` computed from a sample of
+/// effective size `n` into an asymptotic p-value via the Kolmogorov
+/// distribution, `P(D >= d) ~= 2 * sum_{j=1}^inf (-1)^(j-1) exp(-2 j^2 lambda^2)`
+/// with `lambda = (sqrt(n) + 0.12 + 0.11 / sqrt(n)) * d`.
+fn kolmogorov_p_value(d: f64, n: f64) -> f64 {
+    if d <= 0.0 {
+        return 1.0;
+    }
+
+    let sqrt_n = n.sqrt();
+    let lambda = (sqrt_n + 0.12 + 0.11 / sqrt_n) * d;
+
+    let mut p_value = 0.0;
+    let mut j = 1;
+    // 200 iterations is far more than needed for 1e-10 convergence on any
+    // legitimate input; it's a backstop against a NaN `lambda` (e.g. from a
+    // misbehaving user-supplied cdf) looping forever.
+    while j <= 200 {
+        let term = (-2.0 * (j * j) as f64 * lambda * lambda).exp();
+        let signed_term = if j % 2 == 1 { term } else { -term };
+        p_value += signed_term;
+        if term < 1e-10 {
+            break;
+        }
+        j += 1;
+    }
+
+    (2.0 * p_value).clamp(0.0, 1.0)
+}
+
+/// Computes the one-sided `D+`/`D-` Kolmogorov-Smirnov statistics of `sample`
+/// against the continuous cdf `cdf`, returned as `(D+, D-)`; the two-sided
+/// `D` is their maximum. `sample` must already be sorted in ascending order.
+fn ks_statistics(sample: &[f64], cdf: impl Fn(f64) -> f64) -> (f64, f64) {
+    let n = sample.len() as f64;
+
+    let (mut d_plus, mut d_minus) = (0.0_f64, 0.0_f64);
+    for (i, &x) in sample.iter().enumerate() {
+        let f = cdf(x);
+        let i = i as f64;
+        d_plus = d_plus.max((i + 1.0) / n - f);
+        d_minus = d_minus.max(f - i / n);
+    }
+
+    (d_plus, d_minus)
+}
+
+/// Performs a one-sample Kolmogorov-Smirnov goodness-of-fit test of `sample`
+/// against the continuous cdf `cdf`, returning `(statistic, p_value)`.
+///
+/// [`Alternative::Greater`] and [`Alternative::Less`] report the one-sided
+/// `D+`/`D-` statistics respectively; [`Alternative::TwoSided`] reports
+/// `D = max(D+, D-)`. The p-value is computed from the asymptotic Kolmogorov
+/// distribution.
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::ks_test;
+/// use statrs::stats_tests::Alternative;
+/// use statrs::distribution::{ContinuousCDF, Uniform};
+/// let dist = Uniform::new(0.0, 1.0).unwrap();
+/// let sample = [0.1, 0.4, 0.5, 0.7, 0.9];
+/// let (d, p_value) = ks_test(&sample, |x| dist.cdf(x), Alternative::TwoSided);
+/// ```
+pub fn ks_test(sample: &[f64], cdf: impl Fn(f64) -> f64, alternative: Alternative) -> (f64, f64) {
+    let mut sorted = sample.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let (d_plus, d_minus) = ks_statistics(&sorted, cdf);
+    let n = sorted.len() as f64;
+
+    let d = match alternative {
+        Alternative::Greater => d_plus,
+        Alternative::Less => d_minus,
+        Alternative::TwoSided => d_plus.max(d_minus),
+    };
+
+    (d, kolmogorov_p_value(d, n))
+}
+
+/// Performs a two-sample Kolmogorov-Smirnov test of the null hypothesis that
+/// `sample1` and `sample2` are drawn from the same continuous distribution,
+/// returning `(statistic, p_value)`.
+///
+/// The statistic is the maximum gap between the two samples' empirical cdfs
+/// over the merged, sorted order, and the p-value uses the effective sample
+/// size `n * m / (n + m)` in the asymptotic Kolmogorov distribution.
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::ks_test_two_sample;
+/// let sample1 = [0.1, 0.2, 0.5, 0.7, 0.9];
+/// let sample2 = [0.05, 0.3, 0.4, 0.6, 0.8];
+/// let (d, p_value) = ks_test_two_sample(&sample1, &sample2);
+/// ```
+pub fn ks_test_two_sample(sample1: &[f64], sample2: &[f64]) -> (f64, f64) {
+    let mut sorted1 = sample1.to_vec();
+    let mut sorted2 = sample2.to_vec();
+    sorted1.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    sorted2.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let (n, m) = (sorted1.len() as f64, sorted2.len() as f64);
+
+    let empirical_cdf = |sorted: &[f64], x: f64| {
+        sorted.partition_point(|&v| v <= x) as f64 / sorted.len() as f64
+    };
+
+    let mut merged: Vec<f64> = sorted1.iter().chain(sorted2.iter()).copied().collect();
+    merged.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let d = merged
+        .iter()
+        .map(|&x| (empirical_cdf(&sorted1, x) - empirical_cdf(&sorted2, x)).abs())
+        .fold(0.0_f64, f64::max);
+
+    let n_eff = n * m / (n + m);
+    (d, kolmogorov_p_value(d, n_eff))
+}
+
+/// Performs a one-sample Kolmogorov-Smirnov test of `sample` against a
+/// discrete reference distribution `dist`, following the Arnold-Emerson
+/// conditional method: the supremum of `|F_n(x) - F(x)|` is evaluated only at
+/// `dist`'s jump points (its support), since that is where the gap between
+/// the empirical and reference cdfs is maximized for a discrete law.
+///
+/// This lets users test a sample against our `Binomial`/`Hypergeometric`/
+/// `Poisson` models, for example.
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::ks_test_discrete;
+/// use statrs::stats_tests::Alternative;
+/// use statrs::distribution::Poisson;
+/// let dist = Poisson::new(2.0).unwrap();
+/// let sample = [0.0, 1.0, 1.0, 2.0, 4.0];
+/// let (d, p_value) = ks_test_discrete(&sample, &dist, Alternative::TwoSided);
+/// ```
+pub fn ks_test_discrete(
+    sample: &[f64],
+    dist: &impl DiscreteCDF<u64, f64>,
+    alternative: Alternative,
+) -> (f64, f64) {
+    let mut sorted = sample.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let n = sorted.len() as f64;
+    let empirical_cdf = |x: f64| sorted.partition_point(|&v| v <= x) as f64 / n;
+
+    let max_k = sorted.iter().cloned().fold(0.0_f64, f64::max).max(0.0) as u64;
+
+    let (mut d_plus, mut d_minus) = (0.0_f64, 0.0_f64);
+    for k in 0..=max_k {
+        let x = k as f64;
+        let f = dist.cdf(k);
+        d_plus = d_plus.max(empirical_cdf(x) - f);
+        d_minus = d_minus.max(f - empirical_cdf(x - 1.0).max(0.0));
+    }
+
+    let d = match alternative {
+        Alternative::Greater => d_plus,
+        Alternative::Less => d_minus,
+        Alternative::TwoSided => d_plus.max(d_minus),
+    };
+
+    (d, kolmogorov_p_value(d, n))
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{Binomial, ContinuousCDF, Uniform};
+    use crate::prec;
+
+    #[test]
+    fn test_ks_test_discrete_against_binomial() {
+        let dist = Binomial::new(0.5, 4).unwrap();
+        let sample = [0.0, 1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 4.0];
+        let (d, p_value) = ks_test_discrete(&sample, &dist, Alternative::TwoSided);
+        prec::assert_abs_diff_eq!(d, 0.3125, epsilon = 1e-10);
+        assert!((0.0..=1.0).contains(&p_value));
+    }
+
+    #[test]
+    fn test_ks_test_uniform_sample() {
+        let dist = Uniform::new(0.0, 1.0).unwrap();
+        let sample = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9];
+        let (d, p_value) = ks_test(&sample, |x| dist.cdf(x), Alternative::TwoSided);
+        prec::assert_abs_diff_eq!(d, 0.1, epsilon = 1e-10);
+        assert!(p_value > 0.9);
+    }
+
+    #[test]
+    fn test_ks_test_rejects_bad_fit() {
+        let dist = Uniform::new(0.0, 1.0).unwrap();
+        // All mass near 0: should badly fail a Uniform(0, 1) fit.
+        let sample = [0.01, 0.02, 0.01, 0.015, 0.005, 0.02, 0.01, 0.015, 0.01, 0.02];
+        let (d, p_value) = ks_test(&sample, |x| dist.cdf(x), Alternative::TwoSided);
+        assert!(d > 0.9);
+        assert!(p_value < 0.01);
+    }
+
+    #[test]
+    fn test_ks_test_two_sample_identical_distributions() {
+        let sample1 = [0.1, 0.2, 0.3, 0.4, 0.5];
+        let sample2 = [0.15, 0.25, 0.35, 0.45, 0.55];
+        let (d, p_value) = ks_test_two_sample(&sample1, &sample2);
+        prec::assert_abs_diff_eq!(d, 0.2, epsilon = 1e-10);
+        assert!(p_value > 0.5);
+    }
+
+    #[test]
+    fn test_ks_test_two_sample_different_distributions() {
+        let sample1 = [0.0, 0.1, 0.2, 0.3, 0.4];
+        let sample2 = [0.6, 0.7, 0.8, 0.9, 1.0];
+        let (d, p_value) = ks_test_two_sample(&sample1, &sample2);
+        assert_eq!(d, 1.0);
+        assert!(p_value < 0.05);
+    }
+}